@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+#[derive(Event)]
+pub struct GrowthEvent;
+
+#[derive(Event)]
+pub struct GameOverEvent;
@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::common_conditions::on_timer};
+
+use crate::events::{GameOverEvent, GrowthEvent};
+use crate::resources::{LastTailPosition, MovementTimer, SnakeConfig, SnakeSegments};
+use crate::states::GameState;
+use crate::systems::{
+    food_spawner, game_over, position_translation, reset_round, setup_camera, size_scaling,
+    snake_eating, snake_growth, snake_movement, snake_movement_input,
+};
+use crate::ui::{
+    despawn_game_over_ui, despawn_menu_ui, game_over_input, menu_input, spawn_game_over_ui,
+    spawn_menu_ui,
+};
+
+/// Ordering labels for the snake's per-frame update systems.
+///
+/// Chained via [`SnakePlugin::build`] so input is always recorded before
+/// movement runs, eating is resolved before growth, and the whole chain
+/// happens in a single, guaranteed order every frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum SnakeMovement {
+    Input,
+    Movement,
+    Eating,
+    Growth,
+}
+
+/// Adds the snake game to an [`App`]: resources, events, and systems.
+///
+/// Arena size and palette are plugin config, so an app embedding the game
+/// can size and theme it without touching the gameplay systems.
+pub struct SnakePlugin {
+    pub arena_width: u32,
+    pub arena_height: u32,
+    pub head_color: Color,
+    pub food_color: Color,
+    pub segment_color: Color,
+    pub clear_color: Color,
+}
+
+impl Default for SnakePlugin {
+    fn default() -> Self {
+        Self {
+            arena_width: 10,
+            arena_height: 10,
+            head_color: Color::rgb(0.7, 0.7, 0.7),
+            food_color: Color::rgb(1.0, 0.0, 1.0),
+            segment_color: Color::rgb(0.3, 0.3, 0.3),
+            clear_color: Color::rgb(0.04, 0.04, 0.04),
+        }
+    }
+}
+
+impl Plugin for SnakePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SnakeConfig {
+            arena_width: self.arena_width,
+            arena_height: self.arena_height,
+            head_color: self.head_color,
+            food_color: self.food_color,
+            segment_color: self.segment_color,
+        })
+        .insert_resource(ClearColor(self.clear_color))
+        .insert_resource(LastTailPosition(None))
+        .insert_resource(SnakeSegments::default())
+        .insert_resource(MovementTimer::default())
+        .add_event::<GrowthEvent>()
+        .add_event::<GameOverEvent>()
+        .add_state::<GameState>()
+        .configure_sets(
+            Update,
+            (
+                SnakeMovement::Input,
+                SnakeMovement::Movement,
+                SnakeMovement::Eating,
+                SnakeMovement::Growth,
+            )
+                .chain(),
+        )
+        .add_systems(Startup, setup_camera)
+        .add_systems(OnEnter(GameState::Menu), spawn_menu_ui)
+        .add_systems(OnExit(GameState::Menu), despawn_menu_ui)
+        .add_systems(Update, menu_input.run_if(in_state(GameState::Menu)))
+        .add_systems(OnEnter(GameState::Playing), reset_round)
+        .add_systems(OnEnter(GameState::GameOver), spawn_game_over_ui)
+        .add_systems(OnExit(GameState::GameOver), despawn_game_over_ui)
+        .add_systems(
+            Update,
+            game_over_input.run_if(in_state(GameState::GameOver)),
+        )
+        .add_systems(Update, (position_translation, size_scaling))
+        .add_systems(
+            Update,
+            food_spawner
+                .run_if(on_timer(Duration::from_secs(1)))
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            snake_movement_input
+                .in_set(SnakeMovement::Input)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (snake_movement, game_over)
+                .chain()
+                .in_set(SnakeMovement::Movement)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            snake_eating
+                .in_set(SnakeMovement::Eating)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            snake_growth
+                .in_set(SnakeMovement::Growth)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
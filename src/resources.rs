@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::components::Position;
+
+pub const BASE_MOVEMENT_INTERVAL_MS: f32 = 150.0;
+pub const MIN_MOVEMENT_INTERVAL_MS: f32 = 60.0;
+
+#[derive(Default, Resource)]
+pub struct SnakeSegments(pub Vec<Entity>);
+
+#[derive(Default, Resource)]
+pub struct LastTailPosition(pub Option<Position>);
+
+#[derive(Resource)]
+pub struct MovementTimer(pub Timer);
+
+impl Default for MovementTimer {
+    fn default() -> Self {
+        Self(Timer::new(
+            Duration::from_millis(BASE_MOVEMENT_INTERVAL_MS as u64),
+            TimerMode::Repeating,
+        ))
+    }
+}
+
+impl MovementTimer {
+    pub fn set_interval_ms(&mut self, interval_ms: f32) {
+        self.0.set_duration(Duration::from_millis(
+            interval_ms.max(MIN_MOVEMENT_INTERVAL_MS) as u64,
+        ));
+    }
+
+    pub fn reset_to_base(&mut self) {
+        self.set_interval_ms(BASE_MOVEMENT_INTERVAL_MS);
+    }
+}
+
+/// Arena size and palette for a [`crate::plugin::SnakePlugin`] instance.
+///
+/// `clear_color` is not part of this resource: it's only ever needed once,
+/// to build the engine's [`ClearColor`] resource, so `SnakePlugin::build`
+/// reads it straight off the plugin's own config instead of storing it here.
+#[derive(Resource, Clone)]
+pub struct SnakeConfig {
+    pub arena_width: u32,
+    pub arena_height: u32,
+    pub head_color: Color,
+    pub food_color: Color,
+    pub segment_color: Color,
+}
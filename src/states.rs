@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+#[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum GameState {
+    #[default]
+    Menu,
+    Playing,
+    GameOver,
+}
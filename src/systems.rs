@@ -0,0 +1,316 @@
+use std::collections::HashSet;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use rand::prelude::random;
+
+use crate::components::{Direction, Food, Position, Size, SnakeHead, SnakeSegment};
+use crate::events::{GameOverEvent, GrowthEvent};
+use crate::resources::{
+    LastTailPosition, MovementTimer, SnakeConfig, SnakeSegments, BASE_MOVEMENT_INTERVAL_MS,
+};
+use crate::states::GameState;
+
+pub fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+}
+
+pub fn spawn_snake(
+    mut commands: Commands,
+    config: Res<SnakeConfig>,
+    mut segments: ResMut<SnakeSegments>,
+) {
+    *segments = SnakeSegments(vec![
+        spawn_head(&mut commands, &config),
+        spawn_segment(commands, &config, Position { x: 3, y: 2 }),
+    ]);
+}
+
+pub fn spawn_head(commands: &mut Commands, config: &SnakeConfig) -> Entity {
+    commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: config.head_color,
+                    ..default()
+                },
+                transform: Transform {
+                    scale: Vec3::new(10.0, 10.0, 10.0),
+                    ..default()
+                },
+                ..default()
+            },
+            SnakeHead {
+                direction: Direction::Up,
+                intention: Direction::Up,
+            },
+            Position { x: 3, y: 3 },
+            Size::square(0.8),
+        ))
+        .id()
+}
+
+pub fn spawn_segment(mut commands: Commands, config: &SnakeConfig, position: Position) -> Entity {
+    commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: config.segment_color,
+                    ..default()
+                },
+                ..default()
+            },
+            SnakeSegment,
+            position,
+            Size::square(0.65),
+        ))
+        .id()
+}
+
+pub fn food_spawner(
+    mut commands: Commands,
+    config: Res<SnakeConfig>,
+    segments_positions: Query<&Position, With<SnakeSegment>>,
+    head_positions: Query<&Position, With<SnakeHead>>,
+) {
+    let occupied: HashSet<Position> = segments_positions
+        .iter()
+        .chain(head_positions.iter())
+        .copied()
+        .collect();
+
+    let arena_cells = (config.arena_width * config.arena_height) as usize;
+    let free_cells = arena_cells.saturating_sub(occupied.len());
+    if free_cells == 0 {
+        println!("food_spawner, arena is full, no room left to spawn food");
+        return;
+    }
+
+    let position = loop {
+        let candidate = Position {
+            x: (random::<f32>() * config.arena_width as f32) as i32,
+            y: (random::<f32>() * config.arena_height as f32) as i32,
+        };
+        if !occupied.contains(&candidate) {
+            break candidate;
+        }
+    };
+
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: config.food_color,
+                ..default()
+            },
+            ..default()
+        },
+        Food,
+        position,
+        Size::square(0.8),
+    ));
+}
+
+/// Bundles `snake_movement`'s resources and queries into one `SystemParam`
+/// so the system itself stays under clippy's argument-count lint.
+#[derive(SystemParam)]
+pub struct SnakeMovementParams<'w, 's> {
+    pub config: Res<'w, SnakeConfig>,
+    pub movement_timer: ResMut<'w, MovementTimer>,
+    pub segments: ResMut<'w, SnakeSegments>,
+    pub last_tail_position: ResMut<'w, LastTailPosition>,
+    pub head_querry: Query<'w, 's, (Entity, &'static mut SnakeHead)>,
+    pub game_over_writer: EventWriter<'w, GameOverEvent>,
+    pub positions_querry: Query<'w, 's, &'static mut Position>,
+}
+
+pub fn snake_movement(time: Res<Time>, mut params: SnakeMovementParams) {
+    if !params.movement_timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let (head_entity, mut head) = match params.head_querry.get_single_mut() {
+        Ok((head_entity_ok, head_ok)) => (head_entity_ok, head_ok),
+        Err(error) => {
+            println!("snake_movement, error trying to access head : {:?}", error);
+            return;
+        }
+    };
+    head.direction = head.intention;
+
+    let segment_positions = params
+        .segments
+        .0
+        .iter()
+        .map(|e| *params.positions_querry.get_mut(*e).unwrap())
+        .collect::<Vec<Position>>();
+
+    let mut head_position = params.positions_querry.get_mut(head_entity).unwrap();
+
+    match &head.direction {
+        Direction::Left => head_position.x -= 1,
+        Direction::Up => head_position.y += 1,
+        Direction::Right => head_position.x += 1,
+        Direction::Down => head_position.y -= 1,
+    }
+
+    if head_position.x < 0
+        || head_position.y < 0
+        || head_position.x as u32 >= params.config.arena_width
+        || head_position.y as u32 >= params.config.arena_height
+    {
+        params.game_over_writer.send(GameOverEvent);
+    }
+
+    if segment_positions.contains(&head_position) {
+        params.game_over_writer.send(GameOverEvent);
+    }
+
+    segment_positions
+        .iter()
+        .zip(params.segments.0.iter().skip(1))
+        .for_each(|(pos, segment)| {
+            *params.positions_querry.get_mut(*segment).unwrap() = *pos;
+        });
+
+    *params.last_tail_position = LastTailPosition(Some(*segment_positions.last().unwrap()));
+}
+
+pub fn snake_movement_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut query_head: Query<&mut SnakeHead>,
+) {
+    let mut head = match query_head.get_single_mut() {
+        Ok(snake_head) => snake_head,
+        Err(error) => {
+            println!(
+                "snake_movement_input, error trying to access head : {:?}",
+                error
+            );
+            return;
+        }
+    };
+
+    let direction_input: Direction = if keyboard_input.pressed(KeyCode::Left) {
+        Direction::Left
+    } else if keyboard_input.pressed(KeyCode::Down) {
+        Direction::Down
+    } else if keyboard_input.pressed(KeyCode::Up) {
+        Direction::Up
+    } else if keyboard_input.pressed(KeyCode::Right) {
+        Direction::Right
+    } else {
+        head.intention
+    };
+
+    if direction_input != head.direction.opposite() {
+        head.intention = direction_input;
+    }
+}
+
+pub fn size_scaling(
+    config: Res<SnakeConfig>,
+    query_window: Query<&Window>,
+    mut query_size: Query<(&Size, &mut Transform)>,
+) {
+    let window = query_window.single();
+    for (sprite_size, mut transform) in query_size.iter_mut() {
+        transform.scale = Vec3::new(
+            sprite_size.width / config.arena_width as f32 * window.width(),
+            sprite_size.height / config.arena_height as f32 * window.height(),
+            1.0,
+        );
+    }
+}
+
+pub fn position_translation(
+    config: Res<SnakeConfig>,
+    query_window: Query<&Window>,
+    mut query_position: Query<(&Position, &mut Transform)>,
+) {
+    fn convert(pos: f32, bound_window: f32, bound_game: f32) -> f32 {
+        let tile_size = bound_window / bound_game;
+        pos / bound_game * bound_window - (bound_window / 2.) + (tile_size / 2.)
+    }
+    let window = query_window.single();
+    for (pos, mut transform) in query_position.iter_mut() {
+        transform.translation = Vec3::new(
+            convert(
+                pos.x as f32,
+                window.width() as f32,
+                config.arena_width as f32,
+            ),
+            convert(
+                pos.y as f32,
+                window.height() as f32,
+                config.arena_height as f32,
+            ),
+            0.0,
+        );
+    }
+}
+
+pub fn snake_eating(
+    mut commands: Commands,
+    mut growth_writer: EventWriter<GrowthEvent>,
+    food_positions: Query<(Entity, &Position), With<Food>>,
+    head_positions: Query<&Position, With<SnakeHead>>,
+) {
+    for head_pos in head_positions.iter() {
+        for (ent, food_pos) in food_positions.iter() {
+            if food_pos == head_pos {
+                commands.entity(ent).despawn();
+                growth_writer.send(GrowthEvent);
+            }
+        }
+    }
+}
+
+pub fn snake_growth(
+    commands: Commands,
+    last_tail_position: Res<LastTailPosition>,
+    config: Res<SnakeConfig>,
+    mut segments: ResMut<SnakeSegments>,
+    mut movement_timer: ResMut<MovementTimer>,
+    mut growth_reader: EventReader<GrowthEvent>,
+) {
+    if growth_reader.read().next().is_some() {
+        segments.0.push(spawn_segment(
+            commands,
+            &config,
+            last_tail_position.0.unwrap(),
+        ));
+
+        let new_interval_ms = BASE_MOVEMENT_INTERVAL_MS * 0.97f32.powi(segments.0.len() as i32);
+        movement_timer.set_interval_ms(new_interval_ms);
+    }
+}
+
+pub fn game_over(
+    mut reader: EventReader<GameOverEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if reader.read().next().is_none() {
+        return;
+    };
+
+    next_state.set(GameState::GameOver);
+}
+
+pub fn reset_round(
+    mut commands: Commands,
+    config: Res<SnakeConfig>,
+    mut movement_timer: ResMut<MovementTimer>,
+    segments: ResMut<SnakeSegments>,
+    foods: Query<Entity, With<Food>>,
+) {
+    for food in foods.iter() {
+        commands.entity(food).despawn();
+    }
+
+    for segment in segments.0.iter() {
+        commands.entity(*segment).despawn();
+    }
+
+    movement_timer.reset_to_base();
+    spawn_snake(commands, config, segments);
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::components::{GameOverUi, MenuUi};
+use crate::resources::SnakeSegments;
+use crate::states::GameState;
+
+fn screen_node() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        ..default()
+    }
+}
+
+fn text(value: impl Into<String>, font_size: f32) -> TextBundle {
+    TextBundle::from_section(
+        value,
+        TextStyle {
+            font_size,
+            color: Color::WHITE,
+            ..default()
+        },
+    )
+}
+
+pub fn spawn_menu_ui(mut commands: Commands) {
+    commands
+        .spawn((screen_node(), MenuUi))
+        .with_children(|parent| {
+            parent.spawn(text("Press Space to play", 32.0));
+        });
+}
+
+pub fn despawn_menu_ui(mut commands: Commands, query: Query<Entity, With<MenuUi>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn menu_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        next_state.set(GameState::Playing);
+    }
+}
+
+pub fn spawn_game_over_ui(mut commands: Commands, segments: Res<SnakeSegments>) {
+    let score = segments.0.len();
+    commands
+        .spawn((screen_node(), GameOverUi))
+        .with_children(|parent| {
+            parent.spawn(text(format!("Final score: {score}"), 32.0));
+            parent.spawn(text("Press Space to restart", 24.0));
+        });
+}
+
+pub fn despawn_game_over_ui(mut commands: Commands, query: Query<Entity, With<GameOverUi>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn game_over_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        next_state.set(GameState::Playing);
+    }
+}